@@ -6,61 +6,69 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint,
     entrypoint::ProgramResult,
+    hash::hash,
     msg,
+    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
+    system_instruction,
     sysvar::Sysvar,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 
+/// Length, in bytes, of the account type discriminator written at the start
+/// of every account this program owns.
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Seed prefix for a bet's `BetState` PDA: `[BET_SEED_PREFIX, creator, name]`.
+const BET_SEED_PREFIX: &[u8] = b"bet";
+
+/// Seed prefix for a bettor's `BettorDetails` PDA: `[BETTOR_SEED_PREFIX, bet_state, bettor]`.
+const BETTOR_SEED_PREFIX: &[u8] = b"bettor";
+
+/// Computes an Anchor-style 8 byte account discriminator from a struct name.
+/// Prefixing every account with one of these stops an attacker from handing
+/// us, say, a `BettorDetails`-owned account where a `BetState` is expected.
+fn account_discriminator(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+    let digest = hash(format!("account:{}", name).as_bytes());
+    let mut discriminator = [0u8; DISCRIMINATOR_LEN];
+    discriminator.copy_from_slice(&digest.to_bytes()[..DISCRIMINATOR_LEN]);
+    discriminator
+}
+
+/// The set of instructions this program understands, Borsh-encoded by the
+/// client. The variant tag takes the place of the old hand-rolled index
+/// byte, and each variant carries exactly the typed payload its handler
+/// needs instead of a raw byte slice to re-parse.
+#[derive(BorshDeserialize, Debug)]
+enum BetInstruction {
+    InitializeBet { name: String, description: String },
+    PlaceWager { party: u8 },
+    SettleOutcome { winner: u8 },
+    ClaimWinnings,
+}
+
 fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8]
 ) -> ProgramResult {
 
-    if instruction_data.len() == 0 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
+    let instruction = BetInstruction::try_from_slice(instruction_data).map_err(|_| {
+        msg!("Didn't find the entrypoint required");
+        ProgramError::InvalidInstructionData
+    })?;
 
     // Now we just check and call the function for each of them.
-    if instruction_data[0] == 0 {
-        return initialize_bet(
-            program_id,
-            accounts,
-            // Notice we pass program_id and accounts as they where 
-            // but we pass a reference to slice of [instruction_data]. 
-            // we do not want the first element in any of our functions.
-            &instruction_data[1..instruction_data.len()],
-        );
-    } else if instruction_data[0] == 1 {
-        return place_wager(
-            program_id,
-            accounts,
-            &instruction_data[1..instruction_data.len()],
-        );
-    } else if instruction_data[0] == 2 {
-        return settle_bet_outcome(
-            program_id,
-            accounts,
-            &instruction_data[1..instruction_data.len()],
-        );
-    } else if instruction_data[0] == 3 {
-        return claim_winnings(
-            program_id,
-            accounts,
-            &instruction_data[1..instruction_data.len()],
-        );
+    match instruction {
+        BetInstruction::InitializeBet { name, description } => {
+            initialize_bet(program_id, accounts, name, description)
+        }
+        BetInstruction::PlaceWager { party } => place_wager(program_id, accounts, party),
+        BetInstruction::SettleOutcome { winner } => settle_bet_outcome(program_id, accounts, winner),
+        BetInstruction::ClaimWinnings => claim_winnings(program_id, accounts),
     }
-
-    // If instruction_data doesn't match we give an error.
-    // Note I have used msg!() macro and passed a string here. 
-    // It is good to do this as this would 
-    // also get printed in the console window if a program fails.
-    msg!("Didn't find the entrypoint required");
-    Err(ProgramError::InvalidInstructionData)
-
 }
 
 entrypoint!(process_instruction);
@@ -69,22 +77,19 @@ entrypoint!(process_instruction);
 fn initialize_bet(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    name: String,
+    description: String,
 ) -> ProgramResult {
 
     let accounts_iter = &mut accounts.iter();
 
-    // The account running this instruction, created by the Solana program
+    // The bet's PDA, which this instruction creates and writes the BetState into
     let writing_account_pda = next_account_info(accounts_iter)?;
 
-    // The account thats calling to initialize the bet
+    // The account thats calling to initialize the bet, and who pays for its PDA
     let creator_account = next_account_info(accounts_iter)?;
 
-    // We want to write in this account, so we want to make sure its owner is the program itself.
-    if writing_account_pda.owner != program_id {
-        msg!("writing_account_pda isn't owned by the program");
-        return Err(ProgramError::IncorrectProgramId);
-    }
+    let system_program = next_account_info(accounts_iter)?;
 
     // Check to see if this transaction was not signed by the creator_accounts public key
     if !creator_account.is_signer {
@@ -92,44 +97,76 @@ fn initialize_bet(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // We try to deserialize the instruction data into our BetState struct to work with
-    // returns the bet state
-    let mut bet_state = BetState::try_from_slice(&instruction_data).expect("Instruction data serialization did not work");
-
-    // Make sure that the creator of the bet state is the one who initialized the bet
-    if bet_state.creator != *creator_account.key {
-        msg!("Invalid instruction data");
+    if name.len() < 5 {
+        msg!("Name of the bet needs to be at least 5 characters long");
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    if bet_state.name.len() < 5 {
-        msg!("Name of the bet needs to be longer than 5 characters");
+    // `name` is used as a PDA seed below, and seeds longer than
+    // `solana_program::pubkey::MAX_SEED_LEN` (32 bytes) make
+    // `find_program_address` panic instead of returning an error.
+    if name.len() > solana_program::pubkey::MAX_SEED_LEN {
+        msg!("Name of the bet must be at most 32 bytes long");
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    if bet_state.description.len() < 10 {
+    if description.len() < 10 {
         msg!("Description of the bet needs to be longer than 10 characters");
         return Err(ProgramError::InvalidAccountData);
     }
 
-    // Get the minimum balance we need in our program account by using the length of our writing program derived account/address
-    let rent_exemption = Rent::get()?.minimum_balance(writing_account_pda.data_len());
-    
-    // And we make sure our program account (`writing_account`) has that much lamports(balance).
-    if **writing_account_pda.lamports.borrow() < rent_exemption {
-        msg!("The balance of writing_account should be more than rent_exemption");
-        return Err(ProgramError::InsufficientFunds);
+    // The creator of the bet state is always the account that signed this instruction
+    let bet_state = BetState {
+        creator: *creator_account.key,
+        name,
+        description,
+        total_pool: 0,
+        party1_pool: 0,
+        party2_pool: 0,
+        winning_pool: 0,
+        outcome: BetOutcome::new(),
+    };
+
+    // Derive the bet's PDA ourselves instead of trusting whatever account the
+    // caller happened to pass in for writing_account_pda.
+    let bet_seeds: &[&[u8]] = &[
+        BET_SEED_PREFIX,
+        creator_account.key.as_ref(),
+        bet_state.name.as_bytes(),
+    ];
+    let (bet_pda, bump) = Pubkey::find_program_address(bet_seeds, program_id);
+    if bet_pda != *writing_account_pda.key {
+        msg!("writing_account_pda does not match the derived bet PDA");
+        return Err(ProgramError::InvalidArgument);
     }
 
-    // Then we can set the initial bet state
-    bet_state.total_pool=0; // Initialize an empty total pool to keep track of total funds
-    bet_state.party1_pool=0; // Initialize an empty pool for party 1
-    bet_state.party2_pool=0; // Initialize an empty pool for party 2
-    bet_state.outcome = BetOutcome::new(); // Initialize a fresh unsettled outcome
-
-    // Serialize the bet state struct into a binary format using serialize 
-    //to write that data thats in our writing account
-    bet_state.serialize(&mut &mut writing_account_pda.data.borrow_mut()[..])?;
+    // Create and rent-fund the bet's PDA ourselves by CPI into the System Program
+    let space = DISCRIMINATOR_LEN + bet_state.try_to_vec()?.len();
+    let rent_exemption = Rent::get()?.minimum_balance(space);
+    invoke_signed(
+        &system_instruction::create_account(
+            creator_account.key,
+            writing_account_pda.key,
+            rent_exemption,
+            space as u64,
+            program_id,
+        ),
+        &[
+            creator_account.clone(),
+            writing_account_pda.clone(),
+            system_program.clone(),
+        ],
+        &[&[
+            BET_SEED_PREFIX,
+            creator_account.key.as_ref(),
+            bet_state.name.as_bytes(),
+            &[bump],
+        ]],
+    )?;
+
+    // Serialize the bet state struct into a binary format using serialize
+    //to write that data thats in our writing account, tagged with BetState's discriminator
+    bet_state.write(writing_account_pda)?;
 
     // Return OK
     Ok(())
@@ -139,7 +176,7 @@ fn initialize_bet(
 fn place_wager(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    party: u8,
 ) -> ProgramResult {
 
     let accounts_iter = &mut accounts.iter();
@@ -148,9 +185,12 @@ fn place_wager(
 
     let wager_amount_pda = next_account_info(accounts_iter)?;
 
+    // The bettor's PDA, which this instruction creates and writes the BettorDetails into
     let bettor_account_pda = next_account_info(accounts_iter)?;
 
-    let creator_account = next_account_info(accounts_iter)?;
+    let bettor_account = next_account_info(accounts_iter)?;
+
+    let system_program = next_account_info(accounts_iter)?;
 
      // We want to write in this account, so we want to make sure its owner is the program itself.
      if writing_account_pda.owner != program_id {
@@ -159,49 +199,118 @@ fn place_wager(
     }
 
     // Check to see if this transaction was not signed by the bettor_account public key
-    if !creator_account.is_signer {
-        msg!("The creator_account should be the signer of this instruction");
+    if !bettor_account.is_signer {
+        msg!("The bettor_account should be the signer of this instruction");
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    //grab the data to create the Bettor struct
-    let mut bettor_details = BettorDetails::try_from_slice(&instruction_data).expect("Error deserializing bettor details data");
+    // The party being backed comes straight from the typed instruction payload
+    // rather than from client-supplied account data, so it can't be spoofed.
+    let (party1, party2) = match party {
+        1 => (true, false),
+        2 => (false, true),
+        _ => {
+            msg!("party must be either 1 or 2");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
 
     // grab the BetState struct out of the writing account's
-    let mut bet_state = BetState::try_from_slice(*writing_account_pda.data.borrow()).expect("Error deserializing the bet state data");
+    let mut bet_state = BetState::read(writing_account_pda)?;
+
+    // A bet that has already been settled can no longer take wagers, otherwise
+    // someone could back the known winner after the fact and claim for free.
+    if bet_state.outcome.party1_result || bet_state.outcome.party2_result {
+        msg!("This bet has already been settled");
+        return Err(ProgramError::InvalidAccountData);
+    }
 
     // get the number of lamports from the bet_aount_pda
     let bet_amount_in_lamports = wager_amount_pda.lamports();
 
-    // get the minimum balance we need in our program account.
-    // We need this rent exemption to make sure our bettor accounts that get created for each bettor doesnt get dropped by Solana
-    let rent_exemption = Rent::get()?.minimum_balance(bettor_account_pda.data_len());
-
-    // And we make sure our program account (`writing_account`) has that much lamports(balance).
-    if **bettor_account_pda.lamports.borrow() < rent_exemption {
-        msg!("The balance of bettor_account should be more than the rent_exemption");
-        return Err(ProgramError::InsufficientFunds);
+    // Build the bettor_details straight from accounts and the typed payload
+    let bettor_details = BettorDetails {
+        bet_placer_address: *bettor_account.key,
+        value: bet_amount_in_lamports,
+        assoc_bet_address: *writing_account_pda.key,
+        party1,
+        party2,
+        claimed: false,
+    };
+
+    // Derive the bettor's PDA ourselves instead of trusting whatever account
+    // the caller happened to pass in for bettor_account_pda.
+    let bettor_seeds: &[&[u8]] = &[
+        BETTOR_SEED_PREFIX,
+        writing_account_pda.key.as_ref(),
+        bettor_account.key.as_ref(),
+    ];
+    let (bettor_pda, bump) = Pubkey::find_program_address(bettor_seeds, program_id);
+    if bettor_pda != *bettor_account_pda.key {
+        msg!("bettor_account_pda does not match the derived bettor PDA");
+        return Err(ProgramError::InvalidArgument);
     }
 
+    // Create and rent-fund the bettor's PDA ourselves by CPI into the System Program
+    let space = DISCRIMINATOR_LEN + bettor_details.try_to_vec()?.len();
+    let rent_exemption = Rent::get()?.minimum_balance(space);
+    invoke_signed(
+        &system_instruction::create_account(
+            bettor_account.key,
+            bettor_account_pda.key,
+            rent_exemption,
+            space as u64,
+            program_id,
+        ),
+        &[
+            bettor_account.clone(),
+            bettor_account_pda.clone(),
+            system_program.clone(),
+        ],
+        &[&[
+            BETTOR_SEED_PREFIX,
+            writing_account_pda.key.as_ref(),
+            bettor_account.key.as_ref(),
+            &[bump],
+        ]],
+    )?;
+
+    // serialize the bettor_details, tagged with BettorDetails' discriminator
+    bettor_details.write(bettor_account_pda)?;
+
+    // set the bet_state info, guarding against overflow rather than silently wrapping
+    bet_state.total_pool = bet_state
+        .total_pool
+        .checked_add(bet_amount_in_lamports)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    if bettor_details.party1 {
+        bet_state.party1_pool = bet_state
+            .party1_pool
+            .checked_add(bet_amount_in_lamports)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+    }
+    if bettor_details.party2 {
+        bet_state.party2_pool = bet_state
+            .party2_pool
+            .checked_add(bet_amount_in_lamports)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+    }
 
-    // Set the bettor_details info
-    bettor_details.value = bet_amount_in_lamports;
-    // **** party 1 and 2 status will be set by the front end solana api code
-    // **** bet placer and assoc bet address will be set by the front end solana api code
-
-    // serialize the bettor_details
-    bettor_details.serialize(&mut &mut bettor_account_pda.data.borrow_mut()[..])?;
-
-    // set the bet_state info
-    bet_state.total_pool += bet_amount_in_lamports;
-    if bettor_details.party1 { bet_state.party1_pool += bet_amount_in_lamports; } 
-    if bettor_details.party2 { bet_state.party2_pool += bet_amount_in_lamports; }
-
-    // move the lamports from bet_amount_account_pda to writing_account_pda BetState
-    **writing_account_pda.try_borrow_mut_lamports()? += **wager_amount_pda.lamports.borrow();
-    **wager_amount_pda.try_borrow_mut_lamports()? = 0;
-
-    bet_state.serialize(&mut &mut writing_account_pda.data.borrow_mut()[..])?;
+    // Move the wager from wager_amount_pda into writing_account_pda. The debit and
+    // credit are computed from the same `bet_amount_in_lamports` value so the two
+    // accounts can never end up out of balance with each other.
+    let wager_source_balance = wager_amount_pda
+        .lamports()
+        .checked_sub(bet_amount_in_lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let bet_destination_balance = writing_account_pda
+        .lamports()
+        .checked_add(bet_amount_in_lamports)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    **wager_amount_pda.try_borrow_mut_lamports()? = wager_source_balance;
+    **writing_account_pda.try_borrow_mut_lamports()? = bet_destination_balance;
+
+    bet_state.write(writing_account_pda)?;
 
     Ok(())
 }
@@ -210,8 +319,65 @@ fn place_wager(
 fn settle_bet_outcome(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    winner: u8,
 ) -> ProgramResult {
+
+    let accounts_iter = &mut accounts.iter();
+
+    let writing_account_pda = next_account_info(accounts_iter)?;
+
+    // The account that is settling the bet. Only the creator of the bet is allowed to do this.
+    let creator_account = next_account_info(accounts_iter)?;
+
+    // We want to write in this account, so we want to make sure its owner is the program itself.
+    if writing_account_pda.owner != program_id {
+        msg!("writing_account_pda isn't owned by the program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Check to see if this transaction was not signed by the creator_account's public key
+    if !creator_account.is_signer {
+        msg!("The creator_account should be the signer of this instruction");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut bet_state = BetState::read(writing_account_pda)?;
+
+    // Only the creator of the bet is allowed to settle its outcome
+    if bet_state.creator != *creator_account.key {
+        msg!("Only the creator of the bet can settle its outcome");
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Guard against double-settlement, the outcome must not already be decided
+    if bet_state.outcome.party1_result || bet_state.outcome.party2_result {
+        msg!("This bet has already been settled");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The winning party comes straight from the typed instruction payload
+    let (party1_result, party2_result) = match winner {
+        1 => (true, false),
+        2 => (false, true),
+        _ => {
+            msg!("Winning party must be either 1 or 2");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    bet_state.outcome.party1_result = party1_result;
+    bet_state.outcome.party2_result = party2_result;
+
+    // Record the winning pool so claim_winnings can compute payouts without
+    // having to re-scan every bettor account.
+    bet_state.winning_pool = if party1_result {
+        bet_state.party1_pool
+    } else {
+        bet_state.party2_pool
+    };
+
+    bet_state.write(writing_account_pda)?;
+
     Ok(())
 }
 
@@ -219,8 +385,108 @@ fn settle_bet_outcome(
 fn claim_winnings(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
 ) -> ProgramResult {
+
+    let accounts_iter = &mut accounts.iter();
+
+    let writing_account_pda = next_account_info(accounts_iter)?;
+
+    let bettor_account_pda = next_account_info(accounts_iter)?;
+
+    // The wallet that placed the wager, this is who gets paid out
+    let bettor_account = next_account_info(accounts_iter)?;
+
+    if writing_account_pda.owner != program_id {
+        msg!("writing_account_pda isn't owned by the program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if bettor_account_pda.owner != program_id {
+        msg!("bettor_account_pda isn't owned by the program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !bettor_account.is_signer {
+        msg!("The bettor_account should be the signer of this instruction");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let bet_state = BetState::read(writing_account_pda)?;
+
+    let mut bettor_details = BettorDetails::read(bettor_account_pda)?;
+
+    if bettor_details.bet_placer_address != *bettor_account.key {
+        msg!("Only the bettor that placed this wager can claim it");
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Make sure this BettorDetails actually belongs to the presented BetState,
+    // otherwise a winning ticket from one bet could be used to drain another.
+    if bettor_details.assoc_bet_address != *writing_account_pda.key {
+        msg!("This wager was not placed on the presented bet");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Re-derive the bettor's PDA from its seeds as a second check that the
+    // presented bettor_account_pda is the one this program would have created
+    // for this bettor on this bet, not just some account with a matching
+    // assoc_bet_address field.
+    let bettor_seeds: &[&[u8]] = &[
+        BETTOR_SEED_PREFIX,
+        writing_account_pda.key.as_ref(),
+        bettor_account.key.as_ref(),
+    ];
+    let (bettor_pda, _bump) = Pubkey::find_program_address(bettor_seeds, program_id);
+    if bettor_pda != *bettor_account_pda.key {
+        msg!("bettor_account_pda does not match the derived bettor PDA");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The bet must have been settled before anyone can claim winnings
+    if !bet_state.outcome.party1_result && !bet_state.outcome.party2_result {
+        msg!("This bet has not been settled yet");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if bettor_details.claimed {
+        msg!("This wager has already been claimed");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let backed_winner = (bettor_details.party1 && bet_state.outcome.party1_result)
+        || (bettor_details.party2 && bet_state.outcome.party2_result);
+
+    if !backed_winner {
+        msg!("This wager did not back the winning party");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if bet_state.winning_pool == 0 {
+        msg!("The winning pool is empty, nothing to pay out");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Parimutuel payout: stake * total_pool / winning_pool
+    let payout = bettor_details
+        .value
+        .checked_mul(bet_state.total_pool)
+        .and_then(|product| product.checked_div(bet_state.winning_pool))
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // Move the payout out of the bet PDA and into the bettor's wallet
+    **writing_account_pda.try_borrow_mut_lamports()? = writing_account_pda
+        .lamports()
+        .checked_sub(payout)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **bettor_account.try_borrow_mut_lamports()? = bettor_account
+        .lamports()
+        .checked_add(payout)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // Mark the ticket as claimed so it cannot be redeemed twice
+    bettor_details.claimed = true;
+    bettor_details.write(bettor_account_pda)?;
+
     Ok(())
 }
 
@@ -233,7 +499,34 @@ struct BetState {
     pub total_pool: u64,
     pub party1_pool: u64,
     pub party2_pool: u64,
-    pub outcome: BetOutcome 
+    pub winning_pool: u64,
+    pub outcome: BetOutcome
+}
+
+impl BetState {
+    fn discriminator() -> [u8; DISCRIMINATOR_LEN] {
+        account_discriminator("BetState")
+    }
+
+    /// Reads a `BetState` out of an account, rejecting it unless the leading
+    /// discriminator bytes match.
+    fn read(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = account.data.borrow();
+        if data.len() < DISCRIMINATOR_LEN || data[..DISCRIMINATOR_LEN] != Self::discriminator() {
+            msg!("Account does not hold a BetState");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::try_from_slice(&data[DISCRIMINATOR_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serializes `self` back into the account, prefixed with the discriminator.
+    fn write(&self, account: &AccountInfo) -> ProgramResult {
+        let mut data = account.data.borrow_mut();
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Self::discriminator());
+        self.serialize(&mut &mut data[DISCRIMINATOR_LEN..])?;
+        Ok(())
+    }
 }
 
 // Bettor struct representing a single bettor
@@ -244,6 +537,33 @@ struct BettorDetails {
     pub assoc_bet_address: Pubkey,
     pub party1: bool,
     pub party2: bool,
+    pub claimed: bool,
+}
+
+impl BettorDetails {
+    fn discriminator() -> [u8; DISCRIMINATOR_LEN] {
+        account_discriminator("BettorDetails")
+    }
+
+    /// Reads a `BettorDetails` out of an account, rejecting it unless the
+    /// leading discriminator bytes match.
+    fn read(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = account.data.borrow();
+        if data.len() < DISCRIMINATOR_LEN || data[..DISCRIMINATOR_LEN] != Self::discriminator() {
+            msg!("Account does not hold a BettorDetails");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::try_from_slice(&data[DISCRIMINATOR_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serializes `self` back into the account, prefixed with the discriminator.
+    fn write(&self, account: &AccountInfo) -> ProgramResult {
+        let mut data = account.data.borrow_mut();
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Self::discriminator());
+        self.serialize(&mut &mut data[DISCRIMINATOR_LEN..])?;
+        Ok(())
+    }
 }
 
 // BetOutcome struct representing the outcome of a bet